@@ -2,9 +2,14 @@ use assert2::assert as fancy_assert;
 
 use dyn_stack::{DynStack, SizeOverflow, StackReq};
 use faer_core::{
-    householder::apply_block_householder_sequence_transpose_on_the_right_in_place,
+    householder::{
+        apply_block_householder_sequence_on_the_left_in_place,
+        apply_block_householder_sequence_transpose_on_the_left_in_place,
+        apply_block_householder_sequence_transpose_on_the_right_in_place,
+    },
     inverse::invert_upper_triangular,
-    permutation::{permute_cols_in_place_req, permute_rows_in_place, PermutationRef},
+    permutation::{permute_cols_in_place_req, permute_rows_in_place, permute_rows_in_place_req, PermutationRef},
+    solve::solve_upper_triangular_in_place,
     temp_mat_req, temp_mat_uninit, zip, ComplexField, Conj, MatMut, MatRef, Parallelism,
 };
 use reborrow::*;
@@ -126,6 +131,936 @@ pub fn invert_in_place_req<T: 'static>(
     ])
 }
 
+/// Computes the least-squares solution (for overdetermined systems) or the minimum-norm solution
+/// (for underdetermined systems) of `A*X = Rhs`, given the QR decomposition with column pivoting
+/// of `A`, and stores the result in `dst`.
+///
+/// This assumes that `A` has full rank `min(nrows, ncols)`.
+///
+/// # Panics
+///
+/// - Panics if the number of rows of `rhs` isn't the same as the number of rows of `qr_factors`.
+/// - Panics if the number of rows of `dst` isn't the same as the number of columns of
+/// `qr_factors`.
+/// - Panics if the number of columns of `dst` isn't the same as the number of columns of `rhs`.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as the number of columns of
+/// `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn solve_lstsq<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    rhs: MatRef<'_, T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) {
+    let m = qr_factors.nrows();
+    let n = qr_factors.ncols();
+    let k = m.min(n);
+    let ncols = rhs.ncols();
+
+    fancy_assert!(rhs.nrows() == m);
+    fancy_assert!((dst.nrows(), dst.ncols()) == (n, ncols));
+    fancy_assert!(householder_factor.ncols() == k);
+    fancy_assert!(householder_factor.nrows() > 0);
+    fancy_assert!(col_perm.len() == n);
+
+    let mut dst = dst;
+    let mut stack = stack;
+
+    // copy rhs, then overwrite it with Qᵀ·rhs
+    temp_mat_uninit! {
+        let (mut qtb, mut stack) = unsafe { temp_mat_uninit::<T>(m, ncols, stack.rb_mut()) };
+    }
+    zip!(qtb.rb_mut(), rhs).for_each(|dst, src| *dst = *src);
+
+    apply_block_householder_sequence_transpose_on_the_left_in_place(
+        qr_factors,
+        householder_factor,
+        Conj::Yes,
+        qtb.rb_mut(),
+        Conj::No,
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    if n <= m {
+        // overdetermined (or square): solve the leading k×k (k == n) triangular block of R
+        // against the leading k rows of Qᵀ·rhs
+        zip!(dst.rb_mut(), qtb.rb().subrows(0, k)).for_each(|dst, src| *dst = *src);
+
+        solve_upper_triangular_in_place(
+            qr_factors.submatrix(0, 0, k, k),
+            Conj::No,
+            dst.rb_mut(),
+            Conj::No,
+            parallelism,
+        );
+
+        // undo the column permutation
+        permute_rows_in_place(dst, col_perm.inverse(), stack);
+        return;
+    }
+
+    // underdetermined (k == m < n): the leading-k/zero-pad basic solution used above is not the
+    // minimum-norm solution, since any choice of the trailing n-k free variables satisfies
+    // `R·z = Qᵀ·rhs`. The minimum-norm solution is `z = Rᴴ·(R·Rᴴ)⁻¹·(Qᵀ·rhs)`, which we get by
+    // explicitly forming the small m×m Hermitian positive semidefinite Gram matrix `R·Rᴴ`,
+    // factoring it as `L·Lᴴ` (Cholesky), and back-substituting.
+    let r = |i: usize, j: usize| {
+        if j >= i {
+            qr_factors.read(i, j)
+        } else {
+            T::zero()
+        }
+    };
+
+    temp_mat_uninit! {
+        let (mut g, mut stack) = unsafe { temp_mat_uninit::<T>(m, m, stack.rb_mut()) };
+    }
+    // workspace holding the forward-substitution result `w` (column 0) and the back-substitution
+    // result `y` (column 1), in place of heap-allocated vectors
+    temp_mat_uninit! {
+        let (mut buf, _) = unsafe { temp_mat_uninit::<T>(m, 2, stack.rb_mut()) };
+    }
+    for i in 0..m {
+        for j in 0..m {
+            let mut sum = T::zero();
+            for p in i.max(j)..n {
+                sum = sum + r(i, p) * r(j, p).conj();
+            }
+            g.write(i, j, sum);
+        }
+    }
+
+    // Cholesky factorization, in place: G = L·Lᴴ, L stored in the lower triangle of `g`
+    for j in 0..m {
+        let mut diag = g.read(j, j).abs();
+        for k in 0..j {
+            diag = diag - g.read(j, k).abs2();
+        }
+        let ljj = T::from_real(diag.sqrt());
+        g.write(j, j, ljj);
+
+        for i in (j + 1)..m {
+            let mut value = g.read(i, j);
+            for k in 0..j {
+                value = value - g.read(i, k) * g.read(j, k).conj();
+            }
+            g.write(i, j, value / ljj);
+        }
+    }
+
+    for col in 0..ncols {
+        // forward substitution: L·w = Qᵀ·rhs
+        for i in 0..m {
+            let mut value = qtb.read(i, col);
+            for k in 0..i {
+                value = value - g.read(i, k) * buf.read(k, 0);
+            }
+            buf.write(i, 0, value / g.read(i, i));
+        }
+
+        // back substitution: Lᴴ·y = w
+        for i in (0..m).rev() {
+            let mut value = buf.read(i, 0);
+            for k in (i + 1)..m {
+                value = value - g.read(k, i).conj() * buf.read(k, 1);
+            }
+            buf.write(i, 1, value / g.read(i, i));
+        }
+
+        // z = Rᴴ·y
+        for j in 0..n {
+            let mut value = T::zero();
+            for i in 0..m {
+                value = value + r(i, j).conj() * buf.read(i, 1);
+            }
+            dst.write(j, col, value);
+        }
+    }
+
+    // undo the column permutation
+    permute_rows_in_place(dst, col_perm.inverse(), stack);
+}
+
+/// Computes the least-squares (or minimum-norm) solution of `A*X = Rhs`, given the QR
+/// decomposition with column pivoting of `A`, and stores the result in the top
+/// `qr_factors.ncols()` rows of `rhs`.
+///
+/// `rhs` must have `max(qr_factors.nrows(), qr_factors.ncols())` rows: its top
+/// `qr_factors.nrows()` rows hold `Rhs` on input, and its top `qr_factors.ncols()` rows hold the
+/// solution on output.
+///
+/// # Panics
+///
+/// - Panics if the number of rows of `rhs` isn't `max(qr_factors.nrows(), qr_factors.ncols())`.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as the number of columns of
+/// `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn solve_lstsq_in_place<T: ComplexField>(
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    rhs: MatMut<'_, T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) {
+    let m = qr_factors.nrows();
+    let n = qr_factors.ncols();
+    let ncols = rhs.ncols();
+
+    fancy_assert!(rhs.nrows() == m.max(n));
+
+    let mut rhs = rhs;
+    let mut stack = stack;
+
+    temp_mat_uninit! {
+        let (mut dst, stack) = unsafe { temp_mat_uninit::<T>(n, ncols, stack) };
+    }
+
+    solve_lstsq(
+        dst.rb_mut(),
+        qr_factors,
+        householder_factor,
+        col_perm,
+        rhs.rb().subrows(0, m),
+        parallelism,
+        stack,
+    );
+
+    zip!(rhs.rb_mut().subrows(0, n), dst.rb()).for_each(|dst, src| *dst = *src);
+}
+
+/// Computes the Moore–Penrose pseudo-inverse `A⁺` of a (possibly rectangular) matrix, given its
+/// QR decomposition with column pivoting, and stores the result in `dst`.
+///
+/// This is computed as the least-squares solution of `A*X = I`, and assumes that `A` has full
+/// rank `min(nrows, ncols)`.
+///
+/// # Panics
+///
+/// - Panics if `dst` doesn't have `qr_factors.ncols()` rows and `qr_factors.nrows()` columns.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as the number of columns of
+/// `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn pseudo_inverse<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) {
+    let m = qr_factors.nrows();
+    let n = qr_factors.ncols();
+
+    fancy_assert!((dst.nrows(), dst.ncols()) == (n, m));
+
+    let mut stack = stack;
+    temp_mat_uninit! {
+        let (mut eye, stack) = unsafe { temp_mat_uninit::<T>(m, m, stack) };
+    }
+    for j in 0..m {
+        for i in 0..m {
+            eye.write(i, j, if i == j { T::one() } else { T::zero() });
+        }
+    }
+
+    solve_lstsq(
+        dst,
+        qr_factors,
+        householder_factor,
+        col_perm,
+        eye.rb(),
+        parallelism,
+        stack,
+    );
+}
+
+/// Computes the Moore–Penrose pseudo-inverse `A⁺` of a square matrix, given its QR decomposition
+/// with column pivoting, and stores the result in `qr_factors`.
+///
+/// # Panics
+///
+/// - Panics if `qr_factors` is not a square matrix.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn pseudo_inverse_in_place<T: ComplexField>(
+    qr_factors: MatMut<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) {
+    fancy_assert!(qr_factors.nrows() == qr_factors.ncols());
+
+    temp_mat_uninit! {
+        let (mut dst, stack) = unsafe {
+            temp_mat_uninit::<T>(qr_factors.nrows(), qr_factors.ncols(), stack)
+        };
+    }
+
+    pseudo_inverse(
+        dst.rb_mut(),
+        qr_factors.rb(),
+        householder_factor,
+        col_perm,
+        parallelism,
+        stack,
+    );
+
+    zip!(qr_factors, dst.rb()).for_each(|dst, src| *dst = *src);
+}
+
+/// Computes the size and alignment of required workspace for computing the least-squares solution
+/// of `A*X = Rhs` out of place, given the QR decomposition with column pivoting of `A`.
+pub fn solve_lstsq_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    rhs_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let _ = parallelism;
+    let k = qr_nrows.min(qr_ncols);
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_nrows, rhs_ncols)?,
+        StackReq::try_any_of([
+            temp_mat_req::<T>(blocksize, rhs_ncols)?,
+            // m×m Gram matrix and the forward-/back-substitution buffer used to compute the
+            // minimum-norm solution for underdetermined systems (k == qr_nrows in that case);
+            // the Gram matrix is still allocated while the buffer, and later the row
+            // permutation, use the workspace after it
+            StackReq::try_all_of([
+                temp_mat_req::<T>(k, k)?,
+                StackReq::try_any_of([
+                    temp_mat_req::<T>(k, 2)?,
+                    permute_rows_in_place_req::<T>(qr_ncols, rhs_ncols)?,
+                ])?,
+            ])?,
+            permute_rows_in_place_req::<T>(qr_ncols, rhs_ncols)?,
+        ])?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for computing the least-squares solution
+/// of `A*X = Rhs` in place, given the QR decomposition with column pivoting of `A`.
+pub fn solve_lstsq_in_place_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    rhs_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_ncols, rhs_ncols)?,
+        solve_lstsq_req::<T>(qr_nrows, qr_ncols, rhs_ncols, blocksize, parallelism)?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for computing the pseudo-inverse out of
+/// place, given the QR decomposition with column pivoting of the original matrix.
+pub fn pseudo_inverse_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_nrows, qr_nrows)?,
+        solve_lstsq_req::<T>(qr_nrows, qr_ncols, qr_nrows, blocksize, parallelism)?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for computing the pseudo-inverse in
+/// place, given the QR decomposition with column pivoting of the original square matrix.
+pub fn pseudo_inverse_in_place_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_nrows, qr_ncols)?,
+        pseudo_inverse_req::<T>(qr_nrows, qr_ncols, blocksize, parallelism)?,
+    ])
+}
+
+/// Threshold used by [`numerical_rank`] and [`invert_truncated`] to decide how small a diagonal
+/// entry of `R` has to be before it is considered numerically zero.
+#[derive(Debug, Clone, Copy)]
+pub enum RankThreshold<T: ComplexField> {
+    /// An entry `R[k, k]` is considered zero if `|R[k, k]| <= tol * |R[0, 0]|`.
+    Relative(T::Real),
+    /// An entry `R[k, k]` is considered zero if `|R[k, k]| <= tol`.
+    Absolute(T::Real),
+}
+
+/// Returns the numerical rank of a matrix, given the `R` factor of its QR decomposition with
+/// column pivoting.
+///
+/// Since column pivoting makes the diagonal of `R` non-increasing in magnitude, this is the
+/// number of leading diagonal entries of `R` that are not considered zero by `threshold`,
+/// scanning from the top left and stopping at the first one that is.
+pub fn numerical_rank<T: ComplexField>(
+    qr_factors: MatRef<'_, T>,
+    threshold: RankThreshold<T>,
+) -> usize {
+    let size = qr_factors.nrows().min(qr_factors.ncols());
+    if size == 0 {
+        return 0;
+    }
+
+    let tol = match threshold {
+        RankThreshold::Relative(tol) => tol * qr_factors.read(0, 0).abs(),
+        RankThreshold::Absolute(tol) => tol,
+    };
+
+    let mut rank = 0;
+    for k in 0..size {
+        if qr_factors.read(k, k).abs() <= tol {
+            break;
+        }
+        rank = k + 1;
+    }
+    rank
+}
+
+/// Computes the truncated (rank-revealing) inverse of a square matrix, given its QR
+/// decomposition with column pivoting, and stores the result in `dst`.
+///
+/// The numerical rank `r` is first determined from the diagonal of `R` using `threshold` (see
+/// [`numerical_rank`]). The trailing `n - r` columns of `R` are then treated as zero instead of
+/// being inverted, which avoids dividing by a numerically zero pivot for (near-)singular inputs.
+/// The detected rank is returned.
+///
+/// # Panics
+///
+/// - Panics if `qr_factors` is not a square matrix.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as `qr_factors`.
+/// - Panics if `dst` doesn't have the same shape as `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn invert_truncated<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    threshold: RankThreshold<T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) -> usize {
+    fancy_assert!(qr_factors.nrows() == qr_factors.ncols());
+    fancy_assert!((dst.nrows(), dst.ncols()) == (qr_factors.nrows(), qr_factors.ncols()));
+    fancy_assert!(householder_factor.ncols() == usize::min(qr_factors.nrows(), qr_factors.ncols()));
+    fancy_assert!(householder_factor.nrows() > 0);
+
+    let rank = numerical_rank(qr_factors, threshold);
+
+    let mut dst = dst;
+    let mut stack = stack;
+
+    // the trailing n-rank columns of R are treated as zero, so the corresponding rows/columns of
+    // R⁻¹ are zero too: only the leading rank×rank block needs to be inverted
+    zip!(dst.rb_mut()).for_each(|dst| *dst = T::zero());
+    invert_upper_triangular(
+        dst.rb_mut().submatrix(0, 0, rank, rank),
+        qr_factors.submatrix(0, 0, rank, rank),
+        Conj::No,
+        parallelism,
+    );
+    dst.rb_mut()
+        .submatrix(0, 0, rank, rank)
+        .cwise()
+        .for_each_triangular_lower(faer_core::zip::Diag::Skip, |dst| *dst = T::zero());
+
+    apply_block_householder_sequence_transpose_on_the_right_in_place(
+        qr_factors,
+        householder_factor,
+        Conj::Yes,
+        dst.rb_mut(),
+        Conj::No,
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    permute_rows_in_place(dst, col_perm.inverse(), stack);
+
+    rank
+}
+
+/// Computes the truncated (rank-revealing) inverse of a square matrix, given its QR
+/// decomposition with column pivoting, and stores the result in `qr_factors`. Returns the
+/// detected numerical rank.
+///
+/// # Panics
+///
+/// Same as [`invert_truncated`].
+#[track_caller]
+pub fn invert_truncated_in_place<T: ComplexField>(
+    qr_factors: MatMut<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    threshold: RankThreshold<T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) -> usize {
+    temp_mat_uninit! {
+        let (mut dst, stack) = unsafe {
+            temp_mat_uninit::<T>(qr_factors.nrows(), qr_factors.ncols(), stack)
+        };
+    }
+
+    let rank = invert_truncated(
+        dst.rb_mut(),
+        qr_factors.rb(),
+        householder_factor,
+        col_perm,
+        threshold,
+        parallelism,
+        stack,
+    );
+
+    zip!(qr_factors, dst.rb()).for_each(|dst, src| *dst = *src);
+
+    rank
+}
+
+/// Computes the size and alignment of required workspace for computing the truncated inverse of a
+/// matrix out of place, given its QR decomposition with column pivoting.
+pub fn invert_truncated_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    invert_req::<T>(qr_nrows, qr_ncols, blocksize, parallelism)
+}
+
+/// Computes the size and alignment of required workspace for computing the truncated inverse of a
+/// matrix in place, given its QR decomposition with column pivoting.
+pub fn invert_truncated_in_place_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_nrows, qr_ncols)?,
+        invert_truncated_req::<T>(qr_nrows, qr_ncols, blocksize, parallelism)?,
+    ])
+}
+
+/// Computes a cheap `O(n²)` estimate of the 1-norm condition number `κ₁(R) = ‖R‖₁ · ‖R⁻¹‖₁` of
+/// the upper-trapezoidal factor `R`, given its QR decomposition with column pivoting, without
+/// forming `R⁻¹`.
+///
+/// This uses the LINPACK-style incremental condition estimator: `‖R⁻¹‖₁` is estimated by solving
+/// `Rᵀy = e` and `Rz = y` for a vector `e` of `±1` entries chosen greedily, one at a time, to
+/// maximize the growth of the partial solution; the estimate is then `‖z‖₁ / ‖y‖₁`.
+///
+/// Returns `+∞` if a zero pivot is encountered on the diagonal of `R`.
+///
+/// # Panics
+///
+/// - Panics if `qr_factors` is not a square matrix.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn condition_number_estimate<T: ComplexField>(
+    qr_factors: MatRef<'_, T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) -> T::Real {
+    let _ = parallelism;
+    fancy_assert!(qr_factors.nrows() == qr_factors.ncols());
+    let n = qr_factors.ncols();
+
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+
+    if n == 0 {
+        return zero;
+    }
+
+    let r = |i: usize, j: usize| qr_factors.read(i, j);
+
+    // ‖R‖₁: the largest absolute column sum of the upper-trapezoidal part of R
+    let mut r_norm1 = zero;
+    for j in 0..n {
+        let mut col_sum = zero;
+        for i in 0..=j {
+            col_sum = col_sum + r(i, j).abs();
+        }
+        if col_sum > r_norm1 {
+            r_norm1 = col_sum;
+        }
+    }
+
+    // workspace holding `w` (column 0), `y` (column 1) and `z` (column 2), used in place of
+    // heap-allocated vectors
+    temp_mat_uninit! {
+        let (mut buf, _) = unsafe { temp_mat_uninit::<T>(n, 3, stack) };
+    }
+    for i in 0..n {
+        buf.write(i, 0, T::zero());
+    }
+
+    // solve Rᵀy = e, picking each e[k] ∈ {+1, -1} greedily
+    for k in 0..n {
+        let rkk = r(k, k);
+        if rkk.abs() == zero {
+            return one / zero;
+        }
+
+        let wk = buf.read(k, 0);
+        let y_plus = (T::one() - wk) / rkk;
+        let y_minus = (T::zero() - T::one() - wk) / rkk;
+
+        let mut sum_plus = y_plus.abs();
+        let mut sum_minus = y_minus.abs();
+        for j in (k + 1)..n {
+            let wj = buf.read(j, 0);
+            sum_plus = sum_plus + (wj + y_plus * r(k, j)).abs();
+            sum_minus = sum_minus + (wj + y_minus * r(k, j)).abs();
+        }
+
+        let yk = if sum_plus >= sum_minus { y_plus } else { y_minus };
+        buf.write(k, 1, yk);
+        for j in (k + 1)..n {
+            let wj = buf.read(j, 0);
+            buf.write(j, 0, wj + yk * r(k, j));
+        }
+    }
+
+    let mut y_norm1 = zero;
+    for k in 0..n {
+        y_norm1 = y_norm1 + buf.read(k, 1).abs();
+    }
+
+    // solve Rz = y by back substitution
+    for k in (0..n).rev() {
+        let rkk = r(k, k);
+        if rkk.abs() == zero {
+            return one / zero;
+        }
+        let mut sum = buf.read(k, 1);
+        for j in (k + 1)..n {
+            sum = sum - r(k, j) * buf.read(j, 2);
+        }
+        buf.write(k, 2, sum / rkk);
+    }
+    let mut z_norm1 = zero;
+    for k in 0..n {
+        z_norm1 = z_norm1 + buf.read(k, 2).abs();
+    }
+
+    if y_norm1 == zero {
+        return one / zero;
+    }
+
+    r_norm1 * (z_norm1 / y_norm1)
+}
+
+/// Computes the size and alignment of required workspace for [`condition_number_estimate`].
+pub fn condition_number_estimate_req<T: ComplexField>(
+    qr_ncols: usize,
+) -> Result<StackReq, SizeOverflow> {
+    temp_mat_req::<T>(qr_ncols, 3)
+}
+
+/// Policy used by [`QrRegularization`] to pick the sign of a diagonal entry of `R` once its
+/// magnitude has been brought up to the regularization threshold.
+#[derive(Debug, Clone, Copy)]
+pub enum QrRegularizationSign {
+    /// Keep the original sign (direction, for complex entries) of the diagonal entry. A zero
+    /// entry is treated as positive.
+    Preserve,
+    /// Force the regularized entry to be a positive real number.
+    Positive,
+}
+
+/// Configuration for [`invert_regularized`], controlling how small diagonal entries of `R` are
+/// perturbed before the reciprocal is taken, mirroring the `LltRegularization`/`LdltRegularization`
+/// pattern used by the Cholesky factorizations.
+#[derive(Debug, Clone, Copy)]
+pub struct QrRegularization<T: ComplexField> {
+    /// Diagonal entries whose magnitude is at or below this threshold (see [`RankThreshold`])
+    /// are perturbed up to it.
+    pub threshold: RankThreshold<T>,
+    /// Sign to give to a perturbed diagonal entry.
+    pub sign: QrRegularizationSign,
+}
+
+/// Computes the inverse of a (possibly rank-deficient or near-singular) square matrix, given its
+/// QR decomposition with column pivoting, perturbing small diagonal entries of `R` according to
+/// `regularization` instead of dividing by a numerically zero pivot, and stores the result in
+/// `dst`. Returns the number of diagonal entries that were perturbed.
+///
+/// # Panics
+///
+/// - Panics if `qr_factors` is not a square matrix.
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if `col_perm` doesn't have the same dimension as `qr_factors`.
+/// - Panics if `dst` doesn't have the same shape as `qr_factors`.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn invert_regularized<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    regularization: QrRegularization<T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) -> usize {
+    fancy_assert!(qr_factors.nrows() == qr_factors.ncols());
+    fancy_assert!((dst.nrows(), dst.ncols()) == (qr_factors.nrows(), qr_factors.ncols()));
+    fancy_assert!(householder_factor.ncols() == usize::min(qr_factors.nrows(), qr_factors.ncols()));
+    fancy_assert!(householder_factor.nrows() > 0);
+
+    let n = qr_factors.ncols();
+    if n == 0 {
+        return 0;
+    }
+
+    let tol = match regularization.threshold {
+        RankThreshold::Relative(tol) => tol * qr_factors.read(0, 0).abs(),
+        RankThreshold::Absolute(tol) => tol,
+    };
+
+    let mut dst = dst;
+    let mut stack = stack;
+
+    // work on a local copy of R, since the diagonal needs to be perturbed before inverting
+    temp_mat_uninit! {
+        let (mut r, mut stack) = unsafe { temp_mat_uninit::<T>(n, n, stack.rb_mut()) };
+    }
+    zip!(r.rb_mut(), qr_factors).for_each(|dst, src| *dst = *src);
+
+    let mut n_regularized = 0;
+    for i in 0..n {
+        let d = r.read(i, i);
+        let mag = d.abs();
+        if mag <= tol {
+            n_regularized += 1;
+            let regularized = match regularization.sign {
+                QrRegularizationSign::Positive => T::from_real(tol),
+                QrRegularizationSign::Preserve => {
+                    if mag == T::Real::zero() {
+                        T::from_real(tol)
+                    } else {
+                        d * T::from_real(tol / mag)
+                    }
+                }
+            };
+            r.write(i, i, regularized);
+        }
+    }
+
+    // invert R
+    invert_upper_triangular(dst.rb_mut(), r.rb(), Conj::No, parallelism);
+
+    // zero bottom part
+    dst.rb_mut()
+        .cwise()
+        .for_each_triangular_lower(faer_core::zip::Diag::Skip, |dst| *dst = T::zero());
+
+    apply_block_householder_sequence_transpose_on_the_right_in_place(
+        qr_factors,
+        householder_factor,
+        Conj::Yes,
+        dst.rb_mut(),
+        Conj::No,
+        parallelism,
+        stack.rb_mut(),
+    );
+
+    permute_rows_in_place(dst, col_perm.inverse(), stack);
+
+    n_regularized
+}
+
+/// Computes the inverse of a (possibly rank-deficient or near-singular) square matrix, given its
+/// QR decomposition with column pivoting, perturbing small diagonal entries of `R` according to
+/// `regularization`, and stores the result in `qr_factors`. Returns the number of diagonal
+/// entries that were perturbed.
+///
+/// # Panics
+///
+/// Same as [`invert_regularized`].
+#[track_caller]
+pub fn invert_regularized_in_place<T: ComplexField>(
+    qr_factors: MatMut<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    col_perm: PermutationRef<'_>,
+    regularization: QrRegularization<T>,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) -> usize {
+    temp_mat_uninit! {
+        let (mut dst, stack) = unsafe {
+            temp_mat_uninit::<T>(qr_factors.nrows(), qr_factors.ncols(), stack)
+        };
+    }
+
+    let n_regularized = invert_regularized(
+        dst.rb_mut(),
+        qr_factors.rb(),
+        householder_factor,
+        col_perm,
+        regularization,
+        parallelism,
+        stack,
+    );
+
+    zip!(qr_factors, dst.rb()).for_each(|dst, src| *dst = *src);
+
+    n_regularized
+}
+
+/// Computes the size and alignment of required workspace for computing the regularized inverse
+/// of a matrix out of place, given its QR decomposition with column pivoting.
+pub fn invert_regularized_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_ncols, qr_ncols)?,
+        invert_req::<T>(qr_nrows, qr_ncols, blocksize, parallelism)?,
+    ])
+}
+
+/// Computes the size and alignment of required workspace for computing the regularized inverse
+/// of a matrix in place, given its QR decomposition with column pivoting.
+pub fn invert_regularized_in_place_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        temp_mat_req::<T>(qr_nrows, qr_ncols)?,
+        invert_regularized_req::<T>(qr_nrows, qr_ncols, blocksize, parallelism)?,
+    ])
+}
+
+/// Selects how many columns of `Q` [`reconstruct_q`] materializes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructionSize {
+    /// Materialize only the leading `min(nrows, ncols)` columns of `Q` (the thin/economy-size
+    /// `Q`).
+    Thin,
+    /// Materialize the full, square `Q`.
+    Full,
+}
+
+/// Materializes the orthonormal (or unitary) `Q` factor, given the packed QR decomposition with
+/// column pivoting, by applying the block Householder sequence to the identity, and stores the
+/// result in `dst`.
+///
+/// # Panics
+///
+/// - Panics if `dst` doesn't have `qr_factors.nrows()` rows, and either
+/// `min(qr_factors.nrows(), qr_factors.ncols())` columns (for [`ReconstructionSize::Thin`]) or
+/// `qr_factors.nrows()` columns (for [`ReconstructionSize::Full`]).
+/// - Panics if the number of columns of `householder_factor` isn't the same as the minimum of the
+/// number of rows and the number of columns of `qr_factors`.
+/// - Panics if the block size is zero.
+/// - Panics if the provided memory in `stack` is insufficient.
+#[track_caller]
+pub fn reconstruct_q<T: ComplexField>(
+    dst: MatMut<'_, T>,
+    qr_factors: MatRef<'_, T>,
+    householder_factor: MatRef<'_, T>,
+    size: ReconstructionSize,
+    parallelism: Parallelism,
+    stack: DynStack<'_>,
+) {
+    let m = qr_factors.nrows();
+    let k = m.min(qr_factors.ncols());
+    let q_ncols = match size {
+        ReconstructionSize::Thin => k,
+        ReconstructionSize::Full => m,
+    };
+
+    fancy_assert!((dst.nrows(), dst.ncols()) == (m, q_ncols));
+    fancy_assert!(householder_factor.ncols() == k);
+    fancy_assert!(householder_factor.nrows() > 0);
+
+    let mut dst = dst;
+    for j in 0..q_ncols {
+        for i in 0..m {
+            dst.write(i, j, if i == j { T::one() } else { T::zero() });
+        }
+    }
+
+    apply_block_householder_sequence_on_the_left_in_place(
+        qr_factors,
+        householder_factor,
+        Conj::No,
+        dst.rb_mut(),
+        Conj::No,
+        parallelism,
+        stack,
+    );
+}
+
+/// Computes the size and alignment of required workspace for [`reconstruct_q`].
+pub fn reconstruct_q_req<T: 'static>(
+    qr_nrows: usize,
+    qr_ncols: usize,
+    blocksize: usize,
+    parallelism: Parallelism,
+) -> Result<StackReq, SizeOverflow> {
+    let _ = qr_ncols;
+    let _ = parallelism;
+    temp_mat_req::<T>(blocksize, qr_nrows)
+}
+
+/// Copies out the upper-trapezoidal `R` factor from the packed QR decomposition `qr_factors`,
+/// zeroing the strictly lower-triangular part, and stores the result in `dst`.
+///
+/// # Panics
+///
+/// - Panics if `dst` doesn't have the same shape as `qr_factors`.
+#[track_caller]
+pub fn reconstruct_r<T: ComplexField>(dst: MatMut<'_, T>, qr_factors: MatRef<'_, T>) {
+    fancy_assert!((dst.nrows(), dst.ncols()) == (qr_factors.nrows(), qr_factors.ncols()));
+
+    let mut dst = dst;
+    for j in 0..qr_factors.ncols() {
+        for i in 0..qr_factors.nrows() {
+            let value = if i <= j { qr_factors.read(i, j) } else { T::zero() };
+            dst.write(i, j, value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +1135,392 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_solve_lstsq_and_pseudo_inverse() {
+        for (m, n) in [(48, 31), (31, 48), (32, 32)] {
+            let mat = Mat::with_dims(|_, _| random_value(), m, n);
+            let rhs = Mat::with_dims(|_, _| random_value(), m, 2);
+            let blocksize = recommended_blocksize::<T>(m, n);
+            let mut qr = mat.clone();
+            let mut householder_factor = Mat::zeros(blocksize, m.min(n));
+
+            let parallelism = faer_core::Parallelism::Rayon(0);
+            let mut perm = vec![0; n];
+            let mut perm_inv = vec![0; n];
+
+            let (_, perm) = qr_in_place(
+                qr.as_mut(),
+                householder_factor.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                parallelism,
+                make_stack!(
+                    qr_in_place_req::<T>(m, n, blocksize, parallelism, Default::default())
+                        .unwrap()
+                ),
+                Default::default(),
+            );
+
+            let mut sol = Mat::zeros(n, 2);
+            solve_lstsq(
+                sol.as_mut(),
+                qr.as_ref(),
+                householder_factor.as_ref(),
+                perm.rb(),
+                rhs.as_ref(),
+                parallelism,
+                make_stack!(solve_lstsq_req::<T>(m, n, 2, blocksize, parallelism).unwrap()),
+            );
+
+            // for the overdetermined case, the normal equations must hold: Aᴴ(A x - b) == 0
+            if m >= n {
+                let residual = &mat * &sol - &rhs;
+                let normal = mat.adjoint().to_owned() * &residual;
+                for i in 0..n {
+                    for j in 0..2 {
+                        assert_approx_eq!(normal[(i, j)], T::zero());
+                    }
+                }
+            }
+
+            let mut pinv = Mat::zeros(n, m);
+            pseudo_inverse(
+                pinv.as_mut(),
+                qr.as_ref(),
+                householder_factor.as_ref(),
+                perm.rb(),
+                parallelism,
+                make_stack!(pseudo_inverse_req::<T>(m, n, blocksize, parallelism).unwrap()),
+            );
+
+            // A⁺ · b must agree with the least-squares solution
+            let from_pinv = &pinv * &rhs;
+            for i in 0..n {
+                for j in 0..2 {
+                    assert_approx_eq!(from_pinv[(i, j)], sol[(i, j)]);
+                }
+            }
+
+            // (A·A⁺)ᴴ == A·A⁺, one of the Moore-Penrose conditions, regardless of the shape of A
+            let aapinv = &mat * &pinv;
+            let aapinv_adjoint = aapinv.adjoint().to_owned();
+            for i in 0..m {
+                for j in 0..m {
+                    assert_approx_eq!(aapinv[(i, j)], aapinv_adjoint[(i, j)]);
+                }
+            }
+
+            if m < n {
+                // underdetermined: the computed solution must exactly satisfy A·x = b...
+                let residual = &mat * &sol - &rhs;
+                for i in 0..m {
+                    for j in 0..2 {
+                        assert_approx_eq!(residual[(i, j)], T::zero());
+                    }
+                }
+
+                // ...and must be the minimum-norm one: perturbing it along any direction in the
+                // null space of A must not decrease its norm. `r - A⁺·A·r` projects an arbitrary
+                // vector `r` onto the null space of `A`.
+                let rand_vec = Mat::with_dims(|_, _| random_value(), n, 1);
+                let null_component = &rand_vec - &(&pinv * &(&mat * &rand_vec));
+
+                let should_be_zero = &mat * &null_component;
+                for i in 0..m {
+                    assert_approx_eq!(should_be_zero[(i, 0)], T::zero());
+                }
+
+                let perturbation = Mat::with_dims(|i, _| null_component[(i, 0)], n, 2);
+                let perturbed = &sol + &perturbation;
+
+                let norm2 = |x: &Mat<T>| -> f64 {
+                    let mut s = 0.0;
+                    for i in 0..x.nrows() {
+                        for j in 0..x.ncols() {
+                            s += x[(i, j)].abs2();
+                        }
+                    }
+                    s
+                };
+                assert!(norm2(&perturbed) >= norm2(&sol) - 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_numerical_rank_and_invert_truncated() {
+        let n = 32;
+        let r = 17;
+
+        // build a rank-r matrix as a sum of r random rank-1 updates
+        let mut mat = Mat::<T>::zeros(n, n);
+        for _ in 0..r {
+            let u = Mat::with_dims(|_, _| random_value(), n, 1);
+            let v = Mat::with_dims(|_, _| random_value(), n, 1);
+            mat = mat + &u * v.adjoint();
+        }
+
+        let blocksize = recommended_blocksize::<T>(n, n);
+        let mut qr = mat.clone();
+        let mut householder_factor = Mat::zeros(blocksize, n);
+
+        let parallelism = faer_core::Parallelism::Rayon(0);
+        let mut perm = vec![0; n];
+        let mut perm_inv = vec![0; n];
+
+        let (_, perm) = qr_in_place(
+            qr.as_mut(),
+            householder_factor.as_mut(),
+            &mut perm,
+            &mut perm_inv,
+            parallelism,
+            make_stack!(
+                qr_in_place_req::<T>(n, n, blocksize, parallelism, Default::default()).unwrap()
+            ),
+            Default::default(),
+        );
+
+        let detected_rank = numerical_rank(qr.as_ref(), RankThreshold::Relative(1e-9));
+        assert!(detected_rank == r);
+
+        let mut inv = Mat::zeros(n, n);
+        let rank = invert_truncated(
+            inv.as_mut(),
+            qr.as_ref(),
+            householder_factor.as_ref(),
+            perm.rb(),
+            RankThreshold::Relative(1e-9),
+            parallelism,
+            make_stack!(invert_truncated_req::<T>(n, n, blocksize, parallelism).unwrap()),
+        );
+        assert!(rank == r);
+
+        // the truncated inverse is a generalized inverse of `mat`: `mat·inv·mat` must recover
+        // `mat`, up to the numerically zero trailing block of R that truncation discards
+        let reconstructed = &mat * &inv * &mat;
+        for i in 0..n {
+            for j in 0..n {
+                assert_approx_eq!(reconstructed[(i, j)], mat[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_condition_number_estimate() {
+        for n in [1, 16, 32] {
+            let mat = Mat::with_dims(|_, _| random_value(), n, n);
+            let blocksize = recommended_blocksize::<T>(n, n);
+            let mut qr = mat.clone();
+            let mut householder_factor = Mat::zeros(blocksize, n);
+
+            let parallelism = faer_core::Parallelism::Rayon(0);
+            let mut perm = vec![0; n];
+            let mut perm_inv = vec![0; n];
+
+            qr_in_place(
+                qr.as_mut(),
+                householder_factor.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                parallelism,
+                make_stack!(
+                    qr_in_place_req::<T>(n, n, blocksize, parallelism, Default::default())
+                        .unwrap()
+                ),
+                Default::default(),
+            );
+
+            // the estimate is a lower bound on the true condition number, and strictly positive
+            // for a (generically) well-conditioned random matrix
+            let kappa = condition_number_estimate(
+                qr.as_ref(),
+                parallelism,
+                make_stack!(condition_number_estimate_req::<T>(n).unwrap()),
+            );
+            assert!(kappa > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_condition_number_estimate_singular() {
+        let n = 16;
+
+        // build a rank-deficient matrix: the trailing diagonal entry of R is numerically zero
+        let mut mat = Mat::<T>::zeros(n, n);
+        for _ in 0..(n - 1) {
+            let u = Mat::with_dims(|_, _| random_value(), n, 1);
+            let v = Mat::with_dims(|_, _| random_value(), n, 1);
+            mat = mat + &u * v.adjoint();
+        }
+
+        let blocksize = recommended_blocksize::<T>(n, n);
+        let mut qr = mat.clone();
+        let mut householder_factor = Mat::zeros(blocksize, n);
+
+        let parallelism = faer_core::Parallelism::Rayon(0);
+        let mut perm = vec![0; n];
+        let mut perm_inv = vec![0; n];
+
+        qr_in_place(
+            qr.as_mut(),
+            householder_factor.as_mut(),
+            &mut perm,
+            &mut perm_inv,
+            parallelism,
+            make_stack!(
+                qr_in_place_req::<T>(n, n, blocksize, parallelism, Default::default()).unwrap()
+            ),
+            Default::default(),
+        );
+
+        // force an exact zero pivot regardless of how the factorization rounded the trailing
+        // (structurally rank-deficient) diagonal entry
+        qr.write(n - 1, n - 1, T::zero());
+
+        let kappa = condition_number_estimate(
+            qr.as_ref(),
+            parallelism,
+            make_stack!(condition_number_estimate_req::<T>(n).unwrap()),
+        );
+        assert!(kappa.is_infinite());
+    }
+
+    #[test]
+    fn test_invert_regularized() {
+        let n = 32;
+        let r = 17;
+
+        // build a rank-r (singular) matrix, whose R factor has n-r tiny diagonal entries
+        let mut mat = Mat::<T>::zeros(n, n);
+        for _ in 0..r {
+            let u = Mat::with_dims(|_, _| random_value(), n, 1);
+            let v = Mat::with_dims(|_, _| random_value(), n, 1);
+            mat = mat + &u * v.adjoint();
+        }
+
+        let blocksize = recommended_blocksize::<T>(n, n);
+        let mut qr = mat.clone();
+        let mut householder_factor = Mat::zeros(blocksize, n);
+
+        let parallelism = faer_core::Parallelism::Rayon(0);
+        let mut perm = vec![0; n];
+        let mut perm_inv = vec![0; n];
+
+        let (_, perm) = qr_in_place(
+            qr.as_mut(),
+            householder_factor.as_mut(),
+            &mut perm,
+            &mut perm_inv,
+            parallelism,
+            make_stack!(
+                qr_in_place_req::<T>(n, n, blocksize, parallelism, Default::default()).unwrap()
+            ),
+            Default::default(),
+        );
+
+        let mut inv = Mat::zeros(n, n);
+        let n_regularized = invert_regularized(
+            inv.as_mut(),
+            qr.as_ref(),
+            householder_factor.as_ref(),
+            perm.rb(),
+            QrRegularization {
+                threshold: RankThreshold::Relative(1e-9),
+                sign: QrRegularizationSign::Preserve,
+            },
+            parallelism,
+            make_stack!(invert_regularized_req::<T>(n, n, blocksize, parallelism).unwrap()),
+        );
+
+        // the n-r numerically zero diagonal entries of R must all have been perturbed
+        assert!(n_regularized == n - r);
+
+        // unlike truncation, regularization doesn't zero out the near-singular directions, it
+        // biases them away from zero, so `mat·inv·mat` only recovers `mat` up to an error on the
+        // order of the regularization threshold, not to machine precision
+        let norm2 = |x: &Mat<T>| -> f64 {
+            let mut s = 0.0;
+            for i in 0..x.nrows() {
+                for j in 0..x.ncols() {
+                    s += x[(i, j)].abs2();
+                }
+            }
+            s
+        };
+        let residual = &mat * &inv * &mat - &mat;
+        assert!(norm2(&residual) <= 1e-6 * norm2(&mat));
+    }
+
+    #[test]
+    fn test_reconstruct_q_and_r() {
+        use faer_core::permutation::permute_cols_in_place;
+
+        // square, tall (more rows than columns) and wide (more columns than rows): exercises
+        // both `ReconstructionSize::Thin` (which differs from `Full` only in the tall case) and
+        // `ReconstructionSize::Full`
+        for (m, n) in [(32, 32), (48, 31), (31, 48)] {
+            let k = m.min(n);
+            let mat = Mat::with_dims(|_, _| random_value(), m, n);
+            let blocksize = recommended_blocksize::<T>(m, n);
+            let mut qr = mat.clone();
+            let mut householder_factor = Mat::zeros(blocksize, k);
+
+            let parallelism = faer_core::Parallelism::Rayon(0);
+            let mut perm = vec![0; n];
+            let mut perm_inv = vec![0; n];
+
+            let (_, perm) = qr_in_place(
+                qr.as_mut(),
+                householder_factor.as_mut(),
+                &mut perm,
+                &mut perm_inv,
+                parallelism,
+                make_stack!(
+                    qr_in_place_req::<T>(m, n, blocksize, parallelism, Default::default())
+                        .unwrap()
+                ),
+                Default::default(),
+            );
+
+            let mut r = Mat::zeros(m, n);
+            reconstruct_r(r.as_mut(), qr.as_ref());
+
+            // A·Pᵀ == Q·R
+            let mut permuted = mat.clone();
+            permute_cols_in_place(
+                permuted.as_mut(),
+                perm.rb(),
+                make_stack!(permute_cols_in_place_req::<T>(m, n).unwrap()),
+            );
+
+            for size in [ReconstructionSize::Thin, ReconstructionSize::Full] {
+                let q_ncols = match size {
+                    ReconstructionSize::Thin => k,
+                    ReconstructionSize::Full => m,
+                };
+
+                let mut q = Mat::zeros(m, q_ncols);
+                reconstruct_q(
+                    q.as_mut(),
+                    qr.as_ref(),
+                    householder_factor.as_ref(),
+                    size,
+                    parallelism,
+                    make_stack!(reconstruct_q_req::<T>(m, n, blocksize, parallelism).unwrap()),
+                );
+
+                // Q has `q_ncols` columns, so only the leading `q_ncols` rows of R are multiplied
+                // against (the trailing rows of R, if any, are all zero)
+                let r_top = Mat::with_dims(|i, j| r[(i, j)], q_ncols, n);
+                let qr_product = &q * &r_top;
+
+                for i in 0..m {
+                    for j in 0..n {
+                        assert_approx_eq!(qr_product[(i, j)], permuted[(i, j)]);
+                    }
+                }
+            }
+        }
+    }
 }